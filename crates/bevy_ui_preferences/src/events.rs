@@ -0,0 +1,169 @@
+use bevy_ecs::prelude::*;
+
+#[cfg(feature = "accent-color")]
+use bevy_color::Color;
+
+#[cfg(feature = "color-gamut")]
+use crate::ColorGamut;
+#[cfg(feature = "color-scheme")]
+use crate::ColorScheme;
+#[cfg(feature = "contrast")]
+use crate::Contrast;
+#[cfg(feature = "forced-colors")]
+use crate::ForcedColors;
+#[cfg(feature = "inverted-colors")]
+use crate::InvertedColors;
+#[cfg(feature = "reduced-data")]
+use crate::ReducedData;
+#[cfg(feature = "reduced-motion")]
+use crate::ReducedMotion;
+#[cfg(feature = "reduced-transparency")]
+use crate::ReducedTransparency;
+#[cfg(feature = "time-format")]
+use crate::TimeFormat;
+use crate::UiPreferences;
+
+/// Fired whenever any field of [`UiPreferences`] changes, carrying the new value.
+///
+/// Prefer one of the per-field events (e.g. [`ColorSchemeChanged`]) if you only
+/// care about a single preference, since those are only fired when that
+/// specific field actually differs from its previous value.
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+pub struct UiPreferencesChanged(pub UiPreferences);
+
+/// Fired when [`UiPreferences::color_scheme`] changes.
+#[cfg(feature = "color-scheme")]
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+pub struct ColorSchemeChanged(pub ColorScheme);
+
+/// Fired when [`UiPreferences::contrast`] changes.
+#[cfg(feature = "contrast")]
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+pub struct ContrastChanged(pub Contrast);
+
+/// Fired when [`UiPreferences::reduced_motion`] changes.
+#[cfg(feature = "reduced-motion")]
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+pub struct ReducedMotionChanged(pub ReducedMotion);
+
+/// Fired when [`UiPreferences::reduced_transparency`] changes.
+#[cfg(feature = "reduced-transparency")]
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+pub struct ReducedTransparencyChanged(pub ReducedTransparency);
+
+/// Fired when [`UiPreferences::accent_color`] changes.
+#[cfg(feature = "accent-color")]
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+pub struct AccentColorChanged(pub Option<Color>);
+
+/// Fired when [`UiPreferences::double_click_interval`] changes.
+#[cfg(feature = "double-click-interval")]
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+pub struct DoubleClickIntervalChanged(pub Option<core::time::Duration>);
+
+/// Fired when [`UiPreferences::forced_colors`] changes.
+#[cfg(feature = "forced-colors")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct ForcedColorsChanged(pub ForcedColors);
+
+/// Fired when [`UiPreferences::inverted_colors`] changes.
+#[cfg(feature = "inverted-colors")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct InvertedColorsChanged(pub InvertedColors);
+
+/// Fired when [`UiPreferences::reduced_data`] changes.
+#[cfg(feature = "reduced-data")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct ReducedDataChanged(pub ReducedData);
+
+/// Fired when [`UiPreferences::color_gamut`] changes.
+#[cfg(feature = "color-gamut")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct ColorGamutChanged(pub ColorGamut);
+
+/// Fired when [`UiPreferences::time_format`] changes.
+#[cfg(feature = "time-format")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct TimeFormatChanged(pub TimeFormat);
+
+/// Fired when [`UiPreferences::text_scale`] changes.
+#[cfg(feature = "text-scale")]
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+pub struct TextScaleChanged(pub Option<f32>);
+
+/// A run condition that is true on every tick a [`ColorSchemeChanged`] event was fired.
+#[cfg(feature = "color-scheme")]
+pub fn color_scheme_changed(mut events: EventReader<ColorSchemeChanged>) -> bool {
+    events.read().count() > 0
+}
+
+/// A run condition that is true on every tick a [`ContrastChanged`] event was fired.
+#[cfg(feature = "contrast")]
+pub fn contrast_changed(mut events: EventReader<ContrastChanged>) -> bool {
+    events.read().count() > 0
+}
+
+/// A run condition that is true on every tick a [`ReducedMotionChanged`] event was fired.
+#[cfg(feature = "reduced-motion")]
+pub fn reduced_motion_changed(mut events: EventReader<ReducedMotionChanged>) -> bool {
+    events.read().count() > 0
+}
+
+/// A run condition that is true on every tick a [`ReducedTransparencyChanged`] event was fired.
+#[cfg(feature = "reduced-transparency")]
+pub fn reduced_transparency_changed(mut events: EventReader<ReducedTransparencyChanged>) -> bool {
+    events.read().count() > 0
+}
+
+/// A run condition that is true on every tick an [`AccentColorChanged`] event was fired.
+#[cfg(feature = "accent-color")]
+pub fn accent_color_changed(mut events: EventReader<AccentColorChanged>) -> bool {
+    events.read().count() > 0
+}
+
+/// A run condition that is true on every tick a [`DoubleClickIntervalChanged`] event was fired.
+#[cfg(feature = "double-click-interval")]
+pub fn double_click_interval_changed(mut events: EventReader<DoubleClickIntervalChanged>) -> bool {
+    events.read().count() > 0
+}
+
+/// A run condition that is true on every tick a [`ForcedColorsChanged`] event was fired.
+#[cfg(feature = "forced-colors")]
+pub fn forced_colors_changed(mut events: EventReader<ForcedColorsChanged>) -> bool {
+    events.read().count() > 0
+}
+
+/// A run condition that is true on every tick an [`InvertedColorsChanged`] event was fired.
+#[cfg(feature = "inverted-colors")]
+pub fn inverted_colors_changed(mut events: EventReader<InvertedColorsChanged>) -> bool {
+    events.read().count() > 0
+}
+
+/// A run condition that is true on every tick a [`ReducedDataChanged`] event was fired.
+#[cfg(feature = "reduced-data")]
+pub fn reduced_data_changed(mut events: EventReader<ReducedDataChanged>) -> bool {
+    events.read().count() > 0
+}
+
+/// A run condition that is true on every tick a [`ColorGamutChanged`] event was fired.
+#[cfg(feature = "color-gamut")]
+pub fn color_gamut_changed(mut events: EventReader<ColorGamutChanged>) -> bool {
+    events.read().count() > 0
+}
+
+/// A run condition that is true on every tick a [`TimeFormatChanged`] event was fired.
+#[cfg(feature = "time-format")]
+pub fn time_format_changed(mut events: EventReader<TimeFormatChanged>) -> bool {
+    events.read().count() > 0
+}
+
+/// A run condition that is true on every tick a [`TextScaleChanged`] event was fired.
+#[cfg(feature = "text-scale")]
+pub fn text_scale_changed(mut events: EventReader<TextScaleChanged>) -> bool {
+    events.read().count() > 0
+}
+
+/// A run condition that is true on every tick a [`UiPreferencesChanged`] event was fired.
+pub fn ui_preferences_changed(mut events: EventReader<UiPreferencesChanged>) -> bool {
+    events.read().count() > 0
+}
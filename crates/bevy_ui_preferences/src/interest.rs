@@ -0,0 +1,171 @@
+use bevy_ecs::prelude::*;
+use bevy_platform::cfg;
+
+bitflags::bitflags! {
+    /// Which system preferences the [`UiPreferencesPlugin`](crate::UiPreferencesPlugin) should
+    /// subscribe to.
+    ///
+    /// Each flag maps onto one of this crate's Cargo features. Subscribing to a
+    /// preference wakes the platform's IO task (DBus on Linux, the registry on
+    /// Windows, etc.) to watch for changes to it, so an app that only reads
+    /// [`ColorScheme`](crate::ColorScheme) can avoid the overhead of watching
+    /// accent color or double-click interval by only setting
+    /// [`PreferenceInterest::COLOR_SCHEME`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Resource)]
+    pub struct PreferenceInterest: u32 {
+        /// Subscribe to [`UiPreferences::color_scheme`](crate::UiPreferences::color_scheme).
+        #[cfg(feature = "color-scheme")]
+        const COLOR_SCHEME = 1 << 0;
+        /// Subscribe to [`UiPreferences::contrast`](crate::UiPreferences::contrast).
+        #[cfg(feature = "contrast")]
+        const CONTRAST = 1 << 1;
+        /// Subscribe to [`UiPreferences::reduced_motion`](crate::UiPreferences::reduced_motion).
+        #[cfg(feature = "reduced-motion")]
+        const REDUCED_MOTION = 1 << 2;
+        /// Subscribe to [`UiPreferences::reduced_transparency`](crate::UiPreferences::reduced_transparency).
+        #[cfg(feature = "reduced-transparency")]
+        const REDUCED_TRANSPARENCY = 1 << 3;
+        /// Subscribe to [`UiPreferences::accent_color`](crate::UiPreferences::accent_color).
+        #[cfg(feature = "accent-color")]
+        const ACCENT_COLOR = 1 << 4;
+        /// Subscribe to [`UiPreferences::double_click_interval`](crate::UiPreferences::double_click_interval).
+        #[cfg(feature = "double-click-interval")]
+        const DOUBLE_CLICK_INTERVAL = 1 << 5;
+        /// Subscribe to [`UiPreferences::forced_colors`](crate::UiPreferences::forced_colors).
+        #[cfg(feature = "forced-colors")]
+        const FORCED_COLORS = 1 << 6;
+        /// Subscribe to [`UiPreferences::inverted_colors`](crate::UiPreferences::inverted_colors).
+        #[cfg(feature = "inverted-colors")]
+        const INVERTED_COLORS = 1 << 7;
+        /// Subscribe to [`UiPreferences::reduced_data`](crate::UiPreferences::reduced_data).
+        #[cfg(feature = "reduced-data")]
+        const REDUCED_DATA = 1 << 8;
+        /// Subscribe to [`UiPreferences::color_gamut`](crate::UiPreferences::color_gamut).
+        #[cfg(feature = "color-gamut")]
+        const COLOR_GAMUT = 1 << 9;
+        /// Subscribe to [`UiPreferences::time_format`](crate::UiPreferences::time_format).
+        #[cfg(feature = "time-format")]
+        const TIME_FORMAT = 1 << 10;
+        /// Subscribe to [`UiPreferences::text_scale`](crate::UiPreferences::text_scale).
+        #[cfg(feature = "text-scale")]
+        const TEXT_SCALE = 1 << 11;
+    }
+}
+
+impl Default for PreferenceInterest {
+    /// Defaults to the union of all Cargo features enabled among those this
+    /// type tracks. Keep this in sync with [`UiPreferences`](crate::UiPreferences)'s
+    /// fields whenever a new one is added.
+    fn default() -> Self {
+        #[allow(unused_mut)]
+        let mut interest = PreferenceInterest::empty();
+        #[cfg(feature = "color-scheme")]
+        {
+            interest |= PreferenceInterest::COLOR_SCHEME;
+        }
+        #[cfg(feature = "contrast")]
+        {
+            interest |= PreferenceInterest::CONTRAST;
+        }
+        #[cfg(feature = "reduced-motion")]
+        {
+            interest |= PreferenceInterest::REDUCED_MOTION;
+        }
+        #[cfg(feature = "reduced-transparency")]
+        {
+            interest |= PreferenceInterest::REDUCED_TRANSPARENCY;
+        }
+        #[cfg(feature = "accent-color")]
+        {
+            interest |= PreferenceInterest::ACCENT_COLOR;
+        }
+        #[cfg(feature = "double-click-interval")]
+        {
+            interest |= PreferenceInterest::DOUBLE_CLICK_INTERVAL;
+        }
+        #[cfg(feature = "forced-colors")]
+        {
+            interest |= PreferenceInterest::FORCED_COLORS;
+        }
+        #[cfg(feature = "inverted-colors")]
+        {
+            interest |= PreferenceInterest::INVERTED_COLORS;
+        }
+        #[cfg(feature = "reduced-data")]
+        {
+            interest |= PreferenceInterest::REDUCED_DATA;
+        }
+        #[cfg(feature = "color-gamut")]
+        {
+            interest |= PreferenceInterest::COLOR_GAMUT;
+        }
+        #[cfg(feature = "time-format")]
+        {
+            interest |= PreferenceInterest::TIME_FORMAT;
+        }
+        #[cfg(feature = "text-scale")]
+        {
+            interest |= PreferenceInterest::TEXT_SCALE;
+        }
+        interest
+    }
+}
+
+cfg::std! {
+    impl PreferenceInterest {
+        /// Translates this set of flags into the corresponding [`mundy::Interest`] subset.
+        pub(crate) fn to_mundy(self) -> mundy::Interest {
+            #[allow(unused_mut)]
+            let mut interest = mundy::Interest::empty();
+            #[cfg(feature = "color-scheme")]
+            if self.contains(PreferenceInterest::COLOR_SCHEME) {
+                interest |= mundy::Interest::COLOR_SCHEME;
+            }
+            #[cfg(feature = "contrast")]
+            if self.contains(PreferenceInterest::CONTRAST) {
+                interest |= mundy::Interest::CONTRAST;
+            }
+            #[cfg(feature = "reduced-motion")]
+            if self.contains(PreferenceInterest::REDUCED_MOTION) {
+                interest |= mundy::Interest::REDUCED_MOTION;
+            }
+            #[cfg(feature = "reduced-transparency")]
+            if self.contains(PreferenceInterest::REDUCED_TRANSPARENCY) {
+                interest |= mundy::Interest::REDUCED_TRANSPARENCY;
+            }
+            #[cfg(feature = "accent-color")]
+            if self.contains(PreferenceInterest::ACCENT_COLOR) {
+                interest |= mundy::Interest::ACCENT_COLOR;
+            }
+            #[cfg(feature = "double-click-interval")]
+            if self.contains(PreferenceInterest::DOUBLE_CLICK_INTERVAL) {
+                interest |= mundy::Interest::DOUBLE_CLICK_INTERVAL;
+            }
+            #[cfg(feature = "forced-colors")]
+            if self.contains(PreferenceInterest::FORCED_COLORS) {
+                interest |= mundy::Interest::FORCED_COLORS;
+            }
+            #[cfg(feature = "inverted-colors")]
+            if self.contains(PreferenceInterest::INVERTED_COLORS) {
+                interest |= mundy::Interest::INVERTED_COLORS;
+            }
+            #[cfg(feature = "reduced-data")]
+            if self.contains(PreferenceInterest::REDUCED_DATA) {
+                interest |= mundy::Interest::REDUCED_DATA;
+            }
+            #[cfg(feature = "color-gamut")]
+            if self.contains(PreferenceInterest::COLOR_GAMUT) {
+                interest |= mundy::Interest::COLOR_GAMUT;
+            }
+            #[cfg(feature = "time-format")]
+            if self.contains(PreferenceInterest::TIME_FORMAT) {
+                interest |= mundy::Interest::TIME_FORMAT;
+            }
+            #[cfg(feature = "text-scale")]
+            if self.contains(PreferenceInterest::TEXT_SCALE) {
+                interest |= mundy::Interest::TEXT_SCALE;
+            }
+            interest
+        }
+    }
+}
@@ -9,6 +9,12 @@
 //! * [`UiPreferences::double_click_interval`]—The maximum amount of time allowed between the first and second click.
 //! * [`ReducedMotion`]—The user's reduced motion preference.
 //! * [`ReducedTransparency`]—The user's reduced transparency preference.
+//! * [`ForcedColors`]—Whether the user has enabled a forced-colors mode.
+//! * [`InvertedColors`]—The user's preference for an inverted-colors display.
+//! * [`ReducedData`]—The user's preference for reduced data usage.
+//! * [`ColorGamut`]—The widest color gamut the user's screen supports.
+//! * [`TimeFormat`]—The user's preference for a 12-hour or 24-hour clock.
+//! * [`UiPreferences::text_scale`]—The system text-scaling factor to multiply UI font sizes by.
 //!
 //! ## Basic Usage
 //!
@@ -24,14 +30,45 @@ cfg::std! {
     use bevy_tasks::IoTaskPool;
 }
 
+mod events;
+mod interest;
+mod overrides;
 mod preferences;
+pub use events::*;
+pub use interest::*;
+pub use overrides::*;
 pub use preferences::*;
 
+#[cfg(all(
+    feature = "derived-theme",
+    feature = "color-scheme",
+    feature = "contrast",
+    feature = "accent-color"
+))]
+mod theme;
+#[cfg(all(
+    feature = "derived-theme",
+    feature = "color-scheme",
+    feature = "contrast",
+    feature = "accent-color"
+))]
+pub use theme::*;
+
 /// The UI preferences prelude.
 ///
 /// This includes the most common types in this crate, re-exported for your convenience.
 pub mod prelude {
+    pub use crate::events::*;
+    pub use crate::interest::*;
+    pub use crate::overrides::*;
     pub use crate::preferences::*;
+    #[cfg(all(
+        feature = "derived-theme",
+        feature = "color-scheme",
+        feature = "contrast",
+        feature = "accent-color"
+    ))]
+    pub use crate::theme::*;
     pub use crate::UiPreferencesPlugin;
 }
 
@@ -42,7 +79,12 @@ pub struct UiPreferencesSystem;
 /// The UI preferences plugin.
 #[derive(Debug, Default)]
 #[non_exhaustive]
-pub struct UiPreferencesPlugin {}
+pub struct UiPreferencesPlugin {
+    /// Which preferences to subscribe to. Defaults to the union of all enabled
+    /// Cargo features; narrow this if your app only reads a subset of
+    /// [`UiPreferences`], e.g. `PreferenceInterest::COLOR_SCHEME`.
+    pub interest: PreferenceInterest,
+}
 
 impl Plugin for UiPreferencesPlugin {
     fn build(&self, app: &mut App) {
@@ -50,27 +92,57 @@ impl Plugin for UiPreferencesPlugin {
         app.register_type::<UiPreferences>();
 
         app.init_resource::<UiPreferences>()
+            .init_resource::<PreferenceOverrides>()
+            .init_resource::<PreferenceSources>()
+            .add_event::<UiPreferencesChanged>()
             .configure_sets(Startup, UiPreferencesSystem)
             .configure_sets(PreUpdate, UiPreferencesSystem);
 
+        #[cfg(feature = "color-scheme")]
+        app.add_event::<ColorSchemeChanged>();
+        #[cfg(feature = "contrast")]
+        app.add_event::<ContrastChanged>();
+        #[cfg(feature = "reduced-motion")]
+        app.add_event::<ReducedMotionChanged>();
+        #[cfg(feature = "reduced-transparency")]
+        app.add_event::<ReducedTransparencyChanged>();
+        #[cfg(feature = "accent-color")]
+        app.add_event::<AccentColorChanged>();
+        #[cfg(feature = "double-click-interval")]
+        app.add_event::<DoubleClickIntervalChanged>();
+        #[cfg(feature = "forced-colors")]
+        app.add_event::<ForcedColorsChanged>();
+        #[cfg(feature = "inverted-colors")]
+        app.add_event::<InvertedColorsChanged>();
+        #[cfg(feature = "reduced-data")]
+        app.add_event::<ReducedDataChanged>();
+        #[cfg(feature = "color-gamut")]
+        app.add_event::<ColorGamutChanged>();
+        #[cfg(feature = "time-format")]
+        app.add_event::<TimeFormatChanged>();
+        #[cfg(feature = "text-scale")]
+        app.add_event::<TextScaleChanged>();
+
         cfg::std! {
-            app.add_systems(
-                Startup,
-                subscribe_to_preferences.in_set(UiPreferencesSystem),
-            )
-            .add_systems(
-                PreUpdate,
-                poll_system_preferences.in_set(UiPreferencesSystem),
-            );
+            app.insert_resource(self.interest)
+                .init_resource::<SystemPreferences>()
+                .add_systems(
+                    Startup,
+                    subscribe_to_preferences.in_set(UiPreferencesSystem),
+                )
+                .add_systems(
+                    PreUpdate,
+                    poll_system_preferences.in_set(UiPreferencesSystem),
+                );
         }
     }
 }
 
 cfg::std! {
     // Note: this function must be called from the main thread.
-    fn subscribe_to_preferences(mut commands: Commands) {
+    fn subscribe_to_preferences(mut commands: Commands, interest: Res<PreferenceInterest>) {
         let (tx, rx) = crossbeam_channel::unbounded();
-        let stream = mundy::Preferences::stream(mundy::Interest::All);
+        let stream = mundy::Preferences::stream(interest.to_mundy());
         IoTaskPool::get()
             .spawn(async move { forward_stream_to_receiver(tx, stream).await })
             .detach();
@@ -90,16 +162,288 @@ cfg::std! {
     #[derive(Debug, Resource)]
     struct Receiver(crossbeam_channel::Receiver<mundy::Preferences>);
 
+    /// The last preferences reported by the system, before [`PreferenceOverrides`] are applied.
+    ///
+    /// Kept separately from [`UiPreferences`] so that changing an override can be
+    /// resolved against the most recent system report without waiting for a new
+    /// one to arrive on the channel (e.g. in a headless test with no desktop
+    /// environment to read from).
+    #[derive(Debug, Default, Clone, Copy, Resource)]
+    struct SystemPreferences(UiPreferences);
+
     fn poll_system_preferences(
         receiver: Res<Receiver>,
+        overrides: Res<PreferenceOverrides>,
+        mut system_res: ResMut<SystemPreferences>,
         mut preferences_res: ResMut<UiPreferences>,
+        mut sources_res: ResMut<PreferenceSources>,
+        mut preferences_changed: EventWriter<UiPreferencesChanged>,
+        #[cfg(feature = "color-scheme")] mut color_scheme_changed: EventWriter<ColorSchemeChanged>,
+        #[cfg(feature = "contrast")] mut contrast_changed: EventWriter<ContrastChanged>,
+        #[cfg(feature = "reduced-motion")] mut reduced_motion_changed: EventWriter<
+            ReducedMotionChanged,
+        >,
+        #[cfg(feature = "reduced-transparency")] mut reduced_transparency_changed: EventWriter<
+            ReducedTransparencyChanged,
+        >,
+        #[cfg(feature = "accent-color")] mut accent_color_changed: EventWriter<AccentColorChanged>,
+        #[cfg(feature = "double-click-interval")] mut double_click_interval_changed: EventWriter<
+            DoubleClickIntervalChanged,
+        >,
+        #[cfg(feature = "forced-colors")] mut forced_colors_changed: EventWriter<
+            ForcedColorsChanged,
+        >,
+        #[cfg(feature = "inverted-colors")] mut inverted_colors_changed: EventWriter<
+            InvertedColorsChanged,
+        >,
+        #[cfg(feature = "reduced-data")] mut reduced_data_changed: EventWriter<ReducedDataChanged>,
+        #[cfg(feature = "color-gamut")] mut color_gamut_changed: EventWriter<ColorGamutChanged>,
+        #[cfg(feature = "time-format")] mut time_format_changed: EventWriter<TimeFormatChanged>,
+        #[cfg(feature = "text-scale")] mut text_scale_changed: EventWriter<TextScaleChanged>,
     ) -> Result {
-        let preferences = match receiver.0.try_recv() {
-            Ok(preferences) => preferences,
-            Err(crossbeam_channel::TryRecvError::Empty) => return Ok(()),
+        match receiver.0.try_recv() {
+            Ok(preferences) => system_res.0 = preferences.into(),
+            Err(crossbeam_channel::TryRecvError::Empty) => {}
             Err(e) => return Err(e.into()),
+        }
+        // Re-resolve on every tick, not just when a new system report arrives, so
+        // that changing `PreferenceOverrides` at runtime takes effect immediately.
+        let system = system_res.0;
+        let previous = *preferences_res;
+
+        #[cfg(feature = "color-scheme")]
+        let (color_scheme, color_scheme_source) = resolve(
+            overrides.color_scheme,
+            system.color_scheme,
+            matches!(system.color_scheme, ColorScheme::NoPreference),
+        );
+        #[cfg(feature = "contrast")]
+        let (contrast, contrast_source) = resolve(
+            overrides.contrast,
+            system.contrast,
+            matches!(system.contrast, Contrast::NoPreference),
+        );
+        #[cfg(feature = "reduced-motion")]
+        let (reduced_motion, reduced_motion_source) = resolve(
+            overrides.reduced_motion,
+            system.reduced_motion,
+            matches!(system.reduced_motion, ReducedMotion::NoPreference),
+        );
+        #[cfg(feature = "reduced-transparency")]
+        let (reduced_transparency, reduced_transparency_source) = resolve(
+            overrides.reduced_transparency,
+            system.reduced_transparency,
+            matches!(system.reduced_transparency, ReducedTransparency::NoPreference),
+        );
+        #[cfg(feature = "accent-color")]
+        let (accent_color, accent_color_source) = match overrides.accent_color {
+            Some(value) => (value, PreferenceSource::Override),
+            None => (
+                system.accent_color,
+                if system.accent_color.is_none() {
+                    PreferenceSource::NoPreference
+                } else {
+                    PreferenceSource::System
+                },
+            ),
         };
-        *preferences_res = preferences.into();
+        #[cfg(feature = "double-click-interval")]
+        let (double_click_interval, double_click_interval_source) =
+            match overrides.double_click_interval {
+                Some(value) => (value, PreferenceSource::Override),
+                None => (
+                    system.double_click_interval,
+                    if system.double_click_interval.is_none() {
+                        PreferenceSource::NoPreference
+                    } else {
+                        PreferenceSource::System
+                    },
+                ),
+            };
+        #[cfg(feature = "forced-colors")]
+        let (forced_colors, forced_colors_source) = resolve(
+            overrides.forced_colors,
+            system.forced_colors,
+            matches!(system.forced_colors, ForcedColors::NoPreference),
+        );
+        #[cfg(feature = "inverted-colors")]
+        let (inverted_colors, inverted_colors_source) = resolve(
+            overrides.inverted_colors,
+            system.inverted_colors,
+            matches!(system.inverted_colors, InvertedColors::NoPreference),
+        );
+        #[cfg(feature = "reduced-data")]
+        let (reduced_data, reduced_data_source) = resolve(
+            overrides.reduced_data,
+            system.reduced_data,
+            matches!(system.reduced_data, ReducedData::NoPreference),
+        );
+        #[cfg(feature = "color-gamut")]
+        let (color_gamut, color_gamut_source) = resolve(
+            overrides.color_gamut,
+            system.color_gamut,
+            matches!(system.color_gamut, ColorGamut::NoPreference),
+        );
+        #[cfg(feature = "time-format")]
+        let (time_format, time_format_source) = resolve(
+            overrides.time_format,
+            system.time_format,
+            matches!(system.time_format, TimeFormat::NoPreference),
+        );
+        #[cfg(feature = "text-scale")]
+        let (text_scale, text_scale_source) = match overrides.text_scale {
+            Some(value) => (value, PreferenceSource::Override),
+            None => (
+                system.text_scale,
+                if system.text_scale.is_none() {
+                    PreferenceSource::NoPreference
+                } else {
+                    PreferenceSource::System
+                },
+            ),
+        };
+
+        let updated = UiPreferences {
+            #[cfg(feature = "color-scheme")]
+            color_scheme,
+            #[cfg(feature = "contrast")]
+            contrast,
+            #[cfg(feature = "reduced-motion")]
+            reduced_motion,
+            #[cfg(feature = "reduced-transparency")]
+            reduced_transparency,
+            #[cfg(feature = "accent-color")]
+            accent_color,
+            #[cfg(feature = "double-click-interval")]
+            double_click_interval,
+            #[cfg(feature = "forced-colors")]
+            forced_colors,
+            #[cfg(feature = "inverted-colors")]
+            inverted_colors,
+            #[cfg(feature = "reduced-data")]
+            reduced_data,
+            #[cfg(feature = "color-gamut")]
+            color_gamut,
+            #[cfg(feature = "time-format")]
+            time_format,
+            #[cfg(feature = "text-scale")]
+            text_scale,
+        };
+        let new_sources = PreferenceSources {
+            #[cfg(feature = "color-scheme")]
+            color_scheme: color_scheme_source,
+            #[cfg(feature = "contrast")]
+            contrast: contrast_source,
+            #[cfg(feature = "reduced-motion")]
+            reduced_motion: reduced_motion_source,
+            #[cfg(feature = "reduced-transparency")]
+            reduced_transparency: reduced_transparency_source,
+            #[cfg(feature = "accent-color")]
+            accent_color: accent_color_source,
+            #[cfg(feature = "double-click-interval")]
+            double_click_interval: double_click_interval_source,
+            #[cfg(feature = "forced-colors")]
+            forced_colors: forced_colors_source,
+            #[cfg(feature = "inverted-colors")]
+            inverted_colors: inverted_colors_source,
+            #[cfg(feature = "reduced-data")]
+            reduced_data: reduced_data_source,
+            #[cfg(feature = "color-gamut")]
+            color_gamut: color_gamut_source,
+            #[cfg(feature = "time-format")]
+            time_format: time_format_source,
+            #[cfg(feature = "text-scale")]
+            text_scale: text_scale_source,
+        };
+        // Compare sources too: an override that resolves to the same value the
+        // system already reports leaves `updated` unchanged but must still be
+        // reflected in `PreferenceSources`, so callers can tell an explicit
+        // toggle apart from one that's merely inherited from the system.
+        if updated == previous && new_sources == *sources_res {
+            return Ok(());
+        }
+
+        #[cfg(feature = "color-scheme")]
+        if updated.color_scheme != previous.color_scheme {
+            color_scheme_changed.write(ColorSchemeChanged(updated.color_scheme));
+        }
+        #[cfg(feature = "contrast")]
+        if updated.contrast != previous.contrast {
+            contrast_changed.write(ContrastChanged(updated.contrast));
+        }
+        #[cfg(feature = "reduced-motion")]
+        if updated.reduced_motion != previous.reduced_motion {
+            reduced_motion_changed.write(ReducedMotionChanged(updated.reduced_motion));
+        }
+        #[cfg(feature = "reduced-transparency")]
+        if updated.reduced_transparency != previous.reduced_transparency {
+            reduced_transparency_changed.write(ReducedTransparencyChanged(
+                updated.reduced_transparency,
+            ));
+        }
+        #[cfg(feature = "accent-color")]
+        if updated.accent_color != previous.accent_color {
+            accent_color_changed.write(AccentColorChanged(updated.accent_color));
+        }
+        #[cfg(feature = "double-click-interval")]
+        if updated.double_click_interval != previous.double_click_interval {
+            double_click_interval_changed.write(DoubleClickIntervalChanged(
+                updated.double_click_interval,
+            ));
+        }
+        #[cfg(feature = "forced-colors")]
+        if updated.forced_colors != previous.forced_colors {
+            forced_colors_changed.write(ForcedColorsChanged(updated.forced_colors));
+        }
+        #[cfg(feature = "inverted-colors")]
+        if updated.inverted_colors != previous.inverted_colors {
+            inverted_colors_changed.write(InvertedColorsChanged(updated.inverted_colors));
+        }
+        #[cfg(feature = "reduced-data")]
+        if updated.reduced_data != previous.reduced_data {
+            reduced_data_changed.write(ReducedDataChanged(updated.reduced_data));
+        }
+        #[cfg(feature = "color-gamut")]
+        if updated.color_gamut != previous.color_gamut {
+            color_gamut_changed.write(ColorGamutChanged(updated.color_gamut));
+        }
+        #[cfg(feature = "time-format")]
+        if updated.time_format != previous.time_format {
+            time_format_changed.write(TimeFormatChanged(updated.time_format));
+        }
+        #[cfg(feature = "text-scale")]
+        if updated.text_scale != previous.text_scale {
+            text_scale_changed.write(TextScaleChanged(updated.text_scale));
+        }
+
+        *sources_res = new_sources;
+        *preferences_res = updated;
+        if updated != previous {
+            preferences_changed.write(UiPreferencesChanged(updated));
+        }
         Ok(())
     }
+
+    #[cfg(any(
+        feature = "color-scheme",
+        feature = "contrast",
+        feature = "reduced-motion",
+        feature = "reduced-transparency",
+        feature = "forced-colors",
+        feature = "inverted-colors",
+        feature = "reduced-data",
+        feature = "color-gamut",
+        feature = "time-format"
+    ))]
+    fn resolve<T>(
+        override_value: Option<T>,
+        system_value: T,
+        system_is_no_preference: bool,
+    ) -> (T, PreferenceSource) {
+        match override_value {
+            Some(value) => (value, PreferenceSource::Override),
+            None if system_is_no_preference => (system_value, PreferenceSource::NoPreference),
+            None => (system_value, PreferenceSource::System),
+        }
+    }
 }
@@ -0,0 +1,132 @@
+#[cfg(feature = "accent-color")]
+use bevy_color::Color;
+use bevy_ecs::prelude::*;
+
+#[cfg(feature = "color-gamut")]
+use crate::ColorGamut;
+#[cfg(feature = "color-scheme")]
+use crate::ColorScheme;
+#[cfg(feature = "contrast")]
+use crate::Contrast;
+#[cfg(feature = "forced-colors")]
+use crate::ForcedColors;
+#[cfg(feature = "inverted-colors")]
+use crate::InvertedColors;
+#[cfg(feature = "reduced-data")]
+use crate::ReducedData;
+#[cfg(feature = "reduced-motion")]
+use crate::ReducedMotion;
+#[cfg(feature = "reduced-transparency")]
+use crate::ReducedTransparency;
+#[cfg(feature = "time-format")]
+use crate::TimeFormat;
+
+/// Lets an app force any field of [`UiPreferences`](crate::UiPreferences) to a
+/// fixed value, regardless of what the system reports.
+///
+/// `poll_system_preferences` resolves overrides so that they always win over
+/// the incoming `mundy::Preferences`. This is the backing resource for an
+/// in-game accessibility menu's "use system setting" vs. an explicit toggle,
+/// and lets tests pin a scheme without a real desktop environment to read
+/// from.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Resource)]
+#[non_exhaustive]
+pub struct PreferenceOverrides {
+    /// Forces [`UiPreferences::color_scheme`](crate::UiPreferences::color_scheme).
+    #[cfg(feature = "color-scheme")]
+    pub color_scheme: Option<ColorScheme>,
+    /// Forces [`UiPreferences::contrast`](crate::UiPreferences::contrast).
+    #[cfg(feature = "contrast")]
+    pub contrast: Option<Contrast>,
+    /// Forces [`UiPreferences::reduced_motion`](crate::UiPreferences::reduced_motion).
+    #[cfg(feature = "reduced-motion")]
+    pub reduced_motion: Option<ReducedMotion>,
+    /// Forces [`UiPreferences::reduced_transparency`](crate::UiPreferences::reduced_transparency).
+    #[cfg(feature = "reduced-transparency")]
+    pub reduced_transparency: Option<ReducedTransparency>,
+    /// Forces [`UiPreferences::accent_color`](crate::UiPreferences::accent_color). The outer
+    /// `Option` is the override itself; the inner `Option` is the forced value
+    /// (`Some(None)` forces "no accent color").
+    #[cfg(feature = "accent-color")]
+    pub accent_color: Option<Option<Color>>,
+    /// Forces [`UiPreferences::double_click_interval`](crate::UiPreferences::double_click_interval).
+    /// The outer `Option` is the override itself; the inner `Option` is the
+    /// forced value (`Some(None)` forces "no double click interval").
+    #[cfg(feature = "double-click-interval")]
+    pub double_click_interval: Option<Option<core::time::Duration>>,
+    /// Forces [`UiPreferences::forced_colors`](crate::UiPreferences::forced_colors).
+    #[cfg(feature = "forced-colors")]
+    pub forced_colors: Option<ForcedColors>,
+    /// Forces [`UiPreferences::inverted_colors`](crate::UiPreferences::inverted_colors).
+    #[cfg(feature = "inverted-colors")]
+    pub inverted_colors: Option<InvertedColors>,
+    /// Forces [`UiPreferences::reduced_data`](crate::UiPreferences::reduced_data).
+    #[cfg(feature = "reduced-data")]
+    pub reduced_data: Option<ReducedData>,
+    /// Forces [`UiPreferences::color_gamut`](crate::UiPreferences::color_gamut).
+    #[cfg(feature = "color-gamut")]
+    pub color_gamut: Option<ColorGamut>,
+    /// Forces [`UiPreferences::time_format`](crate::UiPreferences::time_format).
+    #[cfg(feature = "time-format")]
+    pub time_format: Option<TimeFormat>,
+    /// Forces [`UiPreferences::text_scale`](crate::UiPreferences::text_scale). The outer
+    /// `Option` is the override itself; the inner `Option` is the forced value
+    /// (`Some(None)` forces "no text scale").
+    #[cfg(feature = "text-scale")]
+    pub text_scale: Option<Option<f32>>,
+}
+
+/// Where a resolved [`UiPreferences`](crate::UiPreferences) field's current value came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PreferenceSource {
+    /// Neither the system nor an override expressed an active preference.
+    #[default]
+    NoPreference,
+    /// The value was reported by the system.
+    System,
+    /// The value was forced by a [`PreferenceOverrides`] field.
+    Override,
+}
+
+/// Tracks the [`PreferenceSource`] of each field currently active in
+/// [`UiPreferences`](crate::UiPreferences), resolved alongside it in `poll_system_preferences`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Resource)]
+#[non_exhaustive]
+pub struct PreferenceSources {
+    /// The source of [`UiPreferences::color_scheme`](crate::UiPreferences::color_scheme).
+    #[cfg(feature = "color-scheme")]
+    pub color_scheme: PreferenceSource,
+    /// The source of [`UiPreferences::contrast`](crate::UiPreferences::contrast).
+    #[cfg(feature = "contrast")]
+    pub contrast: PreferenceSource,
+    /// The source of [`UiPreferences::reduced_motion`](crate::UiPreferences::reduced_motion).
+    #[cfg(feature = "reduced-motion")]
+    pub reduced_motion: PreferenceSource,
+    /// The source of [`UiPreferences::reduced_transparency`](crate::UiPreferences::reduced_transparency).
+    #[cfg(feature = "reduced-transparency")]
+    pub reduced_transparency: PreferenceSource,
+    /// The source of [`UiPreferences::accent_color`](crate::UiPreferences::accent_color).
+    #[cfg(feature = "accent-color")]
+    pub accent_color: PreferenceSource,
+    /// The source of [`UiPreferences::double_click_interval`](crate::UiPreferences::double_click_interval).
+    #[cfg(feature = "double-click-interval")]
+    pub double_click_interval: PreferenceSource,
+    /// The source of [`UiPreferences::forced_colors`](crate::UiPreferences::forced_colors).
+    #[cfg(feature = "forced-colors")]
+    pub forced_colors: PreferenceSource,
+    /// The source of [`UiPreferences::inverted_colors`](crate::UiPreferences::inverted_colors).
+    #[cfg(feature = "inverted-colors")]
+    pub inverted_colors: PreferenceSource,
+    /// The source of [`UiPreferences::reduced_data`](crate::UiPreferences::reduced_data).
+    #[cfg(feature = "reduced-data")]
+    pub reduced_data: PreferenceSource,
+    /// The source of [`UiPreferences::color_gamut`](crate::UiPreferences::color_gamut).
+    #[cfg(feature = "color-gamut")]
+    pub color_gamut: PreferenceSource,
+    /// The source of [`UiPreferences::time_format`](crate::UiPreferences::time_format).
+    #[cfg(feature = "time-format")]
+    pub time_format: PreferenceSource,
+    /// The source of [`UiPreferences::text_scale`](crate::UiPreferences::text_scale).
+    #[cfg(feature = "text-scale")]
+    pub text_scale: PreferenceSource,
+}
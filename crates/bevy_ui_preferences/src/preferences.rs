@@ -38,6 +38,26 @@ pub struct UiPreferences {
     /// event for it to count as double click.
     #[cfg(feature = "double-click-interval")]
     pub double_click_interval: Option<core::time::Duration>,
+    /// Whether the user has enabled a forced-colors mode (e.g. Windows High Contrast).
+    #[cfg(feature = "forced-colors")]
+    pub forced_colors: ForcedColors,
+    /// The user's preference for an inverted-colors display.
+    #[cfg(feature = "inverted-colors")]
+    pub inverted_colors: InvertedColors,
+    /// The user's preference for reduced data usage.
+    #[cfg(feature = "reduced-data")]
+    pub reduced_data: ReducedData,
+    /// The widest color gamut the user's screen supports.
+    #[cfg(feature = "color-gamut")]
+    pub color_gamut: ColorGamut,
+    /// The user's preference for a 12-hour or 24-hour clock.
+    #[cfg(feature = "time-format")]
+    pub time_format: TimeFormat,
+    /// The system text-scaling factor (e.g. GNOME's `text-scaling-factor`, or
+    /// macOS/Windows font scaling), to multiply UI font sizes by.
+    /// `None` indicates no active preference.
+    #[cfg(feature = "text-scale")]
+    pub text_scale: Option<f32>,
 }
 
 cfg::std! {
@@ -56,6 +76,18 @@ cfg::std! {
                 accent_color: to_bevy_color(value.accent_color),
                 #[cfg(feature = "double-click-interval")]
                 double_click_interval: value.double_click_interval.0,
+                #[cfg(feature = "forced-colors")]
+                forced_colors: value.forced_colors.into(),
+                #[cfg(feature = "inverted-colors")]
+                inverted_colors: value.inverted_colors.into(),
+                #[cfg(feature = "reduced-data")]
+                reduced_data: value.reduced_data.into(),
+                #[cfg(feature = "color-gamut")]
+                color_gamut: value.color_gamut.into(),
+                #[cfg(feature = "time-format")]
+                time_format: value.time_format.into(),
+                #[cfg(feature = "text-scale")]
+                text_scale: value.text_scale,
             }
         }
     }
@@ -291,3 +323,268 @@ cfg::std! {
             .map(|c| Srgba::from_f32_array(c.to_f64_array().map(|c| c as f32)).into())
     }
 }
+
+/// Whether the user has enabled a forced-colors mode (e.g. Windows High Contrast),
+/// which overrides author-specified colors with a limited, user-chosen palette.
+/// This corresponds to the [`forced-colors`] CSS media feature.
+///
+/// [`forced-colors`]: https://developer.mozilla.org/en-US/docs/Web/CSS/@media/forced-colors
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "forced-colors")]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Clone, PartialEq, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum ForcedColors {
+    /// Indicates that the user has not expressed an active preference,
+    /// that the current platform doesn't support a forced-colors preference
+    /// or that an error occurred while trying to retrieve the preference.
+    #[default]
+    NoPreference,
+    /// Indicates that a forced-colors mode is active.
+    Active,
+}
+
+#[cfg(feature = "forced-colors")]
+cfg::std! {
+    impl From<mundy::ForcedColors> for ForcedColors {
+        fn from(value: mundy::ForcedColors) -> Self {
+            match value {
+                mundy::ForcedColors::NoPreference => ForcedColors::NoPreference,
+                mundy::ForcedColors::Active => ForcedColors::Active,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "forced-colors")]
+impl ForcedColors {
+    fn is_no_preference(self) -> bool {
+        matches!(self, ForcedColors::NoPreference)
+    }
+
+    fn is_active(self) -> bool {
+        matches!(self, ForcedColors::Active)
+    }
+}
+
+/// The user's preference for an inverted-colors display.
+/// This corresponds to the [`inverted-colors`] CSS media feature.
+///
+/// [`inverted-colors`]: https://developer.mozilla.org/en-US/docs/Web/CSS/@media/inverted-colors
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "inverted-colors")]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Clone, PartialEq, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum InvertedColors {
+    /// Indicates that the user has not expressed an active preference,
+    /// that the current platform doesn't support an inverted-colors preference
+    /// or that an error occurred while trying to retrieve the preference.
+    #[default]
+    NoPreference,
+    /// Indicates that the user's display colors are inverted.
+    Inverted,
+}
+
+#[cfg(feature = "inverted-colors")]
+cfg::std! {
+    impl From<mundy::InvertedColors> for InvertedColors {
+        fn from(value: mundy::InvertedColors) -> Self {
+            match value {
+                mundy::InvertedColors::NoPreference => InvertedColors::NoPreference,
+                mundy::InvertedColors::Inverted => InvertedColors::Inverted,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "inverted-colors")]
+impl InvertedColors {
+    fn is_no_preference(self) -> bool {
+        matches!(self, InvertedColors::NoPreference)
+    }
+
+    fn is_inverted(self) -> bool {
+        matches!(self, InvertedColors::Inverted)
+    }
+}
+
+/// The user prefers that apps minimize the amount of network data they use.
+/// This corresponds to the [`prefers-reduced-data`] CSS media feature.
+///
+/// [`prefers-reduced-data`]: https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-reduced-data
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "reduced-data")]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Clone, PartialEq, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum ReducedData {
+    /// Indicates that the user has not expressed an active preference,
+    /// that the current platform doesn't support a reduced data preference
+    /// or that an error occurred while trying to retrieve the preference.
+    #[default]
+    NoPreference,
+    /// Indicates that the user prefers apps minimize the amount of network data they use.
+    Reduce,
+}
+
+#[cfg(feature = "reduced-data")]
+cfg::std! {
+    impl From<mundy::ReducedData> for ReducedData {
+        fn from(value: mundy::ReducedData) -> Self {
+            match value {
+                mundy::ReducedData::NoPreference => ReducedData::NoPreference,
+                mundy::ReducedData::Reduce => ReducedData::Reduce,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "reduced-data")]
+impl ReducedData {
+    fn is_no_preference(self) -> bool {
+        matches!(self, ReducedData::NoPreference)
+    }
+
+    fn is_reduce(self) -> bool {
+        matches!(self, ReducedData::Reduce)
+    }
+}
+
+/// The widest color gamut the user's screen supports.
+/// This corresponds to the [`color-gamut`] CSS media feature.
+///
+/// [`color-gamut`]: https://developer.mozilla.org/en-US/docs/Web/CSS/@media/color-gamut
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "color-gamut")]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Clone, PartialEq, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum ColorGamut {
+    /// Indicates that the user has not expressed an active preference,
+    /// that the current platform doesn't support a color-gamut query
+    /// or that an error occurred while trying to retrieve the preference.
+    #[default]
+    NoPreference,
+    /// Indicates that the screen can display at least the `sRGB` gamut.
+    Srgb,
+    /// Indicates that the screen can display at least the `P3` gamut.
+    P3,
+    /// Indicates that the screen can display at least the `Rec. 2020` gamut.
+    Rec2020,
+}
+
+#[cfg(feature = "color-gamut")]
+cfg::std! {
+    impl From<mundy::ColorGamut> for ColorGamut {
+        fn from(value: mundy::ColorGamut) -> Self {
+            match value {
+                mundy::ColorGamut::NoPreference => ColorGamut::NoPreference,
+                mundy::ColorGamut::Srgb => ColorGamut::Srgb,
+                mundy::ColorGamut::P3 => ColorGamut::P3,
+                mundy::ColorGamut::Rec2020 => ColorGamut::Rec2020,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "color-gamut")]
+impl ColorGamut {
+    fn is_no_preference(self) -> bool {
+        matches!(self, ColorGamut::NoPreference)
+    }
+
+    fn is_srgb(self) -> bool {
+        matches!(self, ColorGamut::Srgb)
+    }
+
+    fn is_p3(self) -> bool {
+        matches!(self, ColorGamut::P3)
+    }
+
+    fn is_rec2020(self) -> bool {
+        matches!(self, ColorGamut::Rec2020)
+    }
+}
+
+/// The user's preference for a 12-hour or 24-hour clock, e.g. GNOME's
+/// `clock-format` setting, read through the platform's generic settings backend.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "time-format")]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Clone, PartialEq, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum TimeFormat {
+    /// Indicates that the user has not expressed an active preference,
+    /// that the current platform doesn't support a time format preference
+    /// or that an error occurred while trying to retrieve the preference.
+    #[default]
+    NoPreference,
+    /// Indicates that the user prefers a 12-hour clock.
+    TwelveHour,
+    /// Indicates that the user prefers a 24-hour clock.
+    TwentyFourHour,
+}
+
+#[cfg(feature = "time-format")]
+cfg::std! {
+    impl From<mundy::TimeFormat> for TimeFormat {
+        fn from(value: mundy::TimeFormat) -> Self {
+            match value {
+                mundy::TimeFormat::NoPreference => TimeFormat::NoPreference,
+                mundy::TimeFormat::TwelveHour => TimeFormat::TwelveHour,
+                mundy::TimeFormat::TwentyFourHour => TimeFormat::TwentyFourHour,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "time-format")]
+impl TimeFormat {
+    fn is_no_preference(self) -> bool {
+        matches!(self, TimeFormat::NoPreference)
+    }
+
+    fn is_twelve_hour(self) -> bool {
+        matches!(self, TimeFormat::TwelveHour)
+    }
+
+    fn is_twenty_four_hour(self) -> bool {
+        matches!(self, TimeFormat::TwentyFourHour)
+    }
+}
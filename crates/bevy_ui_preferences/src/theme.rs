@@ -0,0 +1,165 @@
+use bevy_app::prelude::*;
+use bevy_color::{Color, LinearRgba, Mix, Srgba};
+use bevy_ecs::prelude::*;
+
+use crate::{ui_preferences_changed, ColorScheme, Contrast, UiPreferences, UiPreferencesSystem};
+
+/// A small, ready-to-use palette derived from [`UiPreferences`].
+///
+/// Recomputed from [`UiPreferences::color_scheme`], [`UiPreferences::contrast`]
+/// and [`UiPreferences::accent_color`] by [`DerivedThemePlugin`], which must be
+/// added alongside [`UiPreferencesPlugin`](crate::UiPreferencesPlugin) to keep
+/// this resource up to date.
+#[derive(Debug, Clone, Copy, PartialEq, Resource)]
+pub struct DerivedTheme {
+    /// The base background color of the UI.
+    pub background: Color,
+    /// A color for elevated surfaces (cards, panels) above the background.
+    pub surface: Color,
+    /// Body text, guaranteed to meet a minimum contrast ratio against `background`.
+    pub text: Color,
+    /// The user's accent color, or a scheme-appropriate default when none is set.
+    pub accent: Color,
+    /// `accent`, shifted for hover/pressed states.
+    pub accent_hover: Color,
+    /// A subdued color for borders and dividers.
+    pub border: Color,
+}
+
+impl Default for DerivedTheme {
+    fn default() -> Self {
+        DerivedTheme::from(&UiPreferences::default())
+    }
+}
+
+impl From<&UiPreferences> for DerivedTheme {
+    fn from(preferences: &UiPreferences) -> Self {
+        let dark = matches!(preferences.color_scheme, ColorScheme::Dark);
+
+        let background = if dark {
+            Srgba::rgb(0.098, 0.098, 0.106)
+        } else {
+            Srgba::rgb(0.980, 0.980, 0.988)
+        };
+        let mut text = if dark {
+            Srgba::rgb(0.953, 0.953, 0.961)
+        } else {
+            Srgba::rgb(0.078, 0.078, 0.090)
+        };
+
+        let default_accent = if dark {
+            Srgba::rgb(0.427, 0.600, 0.965)
+        } else {
+            Srgba::rgb(0.165, 0.384, 0.878)
+        };
+        let accent = preferences
+            .accent_color
+            .map(Srgba::from)
+            .unwrap_or(default_accent);
+
+        // Tint surfaces and borders toward the opposite end of the scheme, so
+        // they read as "elevated" above the background.
+        let toward_foreground = if dark { Srgba::WHITE } else { Srgba::BLACK };
+        let surface = background.mix(&toward_foreground, if dark { 0.06 } else { 0.04 });
+        let border = background.mix(&toward_foreground, if dark { 0.16 } else { 0.12 });
+        let accent_hover = accent.mix(&toward_foreground, 0.15);
+
+        if preferences.contrast == Contrast::Custom {
+            // `UiPreferences` carries no system-provided foreground/background
+            // pair to forward verbatim, so forced-colors mode instead pins
+            // every role to the system's two-color scheme but keeps border and
+            // accent visually distinct from text: the border leans toward the
+            // background rather than all the way to text, and the accent is
+            // still clamped to the same minimum contrast as the "More" level.
+            let accent = ensure_contrast(accent, background, toward_foreground, 7.0);
+            return DerivedTheme {
+                background: background.into(),
+                surface: background.into(),
+                text: text.into(),
+                accent: accent.into(),
+                accent_hover: accent.mix(&toward_foreground, 0.15).into(),
+                border: background.mix(&toward_foreground, 0.5).into(),
+            };
+        }
+
+        match preferences.contrast {
+            Contrast::Less => {
+                // Intentionally flatten text toward the background, then claw
+                // back just enough contrast to stay legible.
+                text = text.mix(&background, 0.18);
+                text = ensure_contrast(text, background, toward_foreground, 3.0);
+            }
+            Contrast::More => {
+                text = ensure_contrast(text, background, toward_foreground, 7.0);
+            }
+            _ => {
+                text = ensure_contrast(text, background, toward_foreground, 4.5);
+            }
+        }
+
+        DerivedTheme {
+            background: background.into(),
+            surface: surface.into(),
+            text: text.into(),
+            accent: accent.into(),
+            accent_hover: accent_hover.into(),
+            border: border.into(),
+        }
+    }
+}
+
+/// Relative luminance per the WCAG definition: `L = 0.2126R + 0.7152G + 0.0722B`
+/// on linearized sRGB channels.
+fn relative_luminance(color: Srgba) -> f32 {
+    let linear = LinearRgba::from(color);
+    0.2126 * linear.red + 0.7152 * linear.green + 0.0722 * linear.blue
+}
+
+/// The WCAG contrast ratio `(Lmax + 0.05) / (Lmin + 0.05)` between two colors.
+fn contrast_ratio(a: Srgba, b: Srgba) -> f32 {
+    let (la, lb) = (relative_luminance(a), relative_luminance(b));
+    let (lighter, darker) = if la >= lb { (la, lb) } else { (lb, la) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// Lightens/darkens `foreground` toward `toward` until it clears `target_ratio`
+/// of contrast against `background`, or until it fully reaches `toward`.
+fn ensure_contrast(
+    mut foreground: Srgba,
+    background: Srgba,
+    toward: Srgba,
+    target_ratio: f32,
+) -> Srgba {
+    let mut step = 0.0;
+    while contrast_ratio(foreground, background) < target_ratio && step < 1.0 {
+        step += 0.05;
+        foreground = foreground.mix(&toward, step);
+    }
+    foreground
+}
+
+/// Opt-in plugin that maintains a [`DerivedTheme`] resource, recomputed
+/// whenever [`UiPreferences`] changes.
+///
+/// Add this alongside [`UiPreferencesPlugin`](crate::UiPreferencesPlugin):
+/// ```ignore
+/// app.add_plugins((UiPreferencesPlugin::default(), DerivedThemePlugin));
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+#[non_exhaustive]
+pub struct DerivedThemePlugin;
+
+impl Plugin for DerivedThemePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DerivedTheme>().add_systems(
+            PreUpdate,
+            update_derived_theme
+                .after(UiPreferencesSystem)
+                .run_if(ui_preferences_changed),
+        );
+    }
+}
+
+fn update_derived_theme(preferences: Res<UiPreferences>, mut theme: ResMut<DerivedTheme>) {
+    *theme = DerivedTheme::from(&*preferences);
+}